@@ -1,13 +1,193 @@
 use core::ops::{Div, Mul};
 use ff::PrimeField;
+use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Radix-2 number-theoretic transform over a field's 2-adic roots of unity, used to move
+/// between the integer-point `Evals` domain and monomial coefficients.
+mod ntt {
+    use ff::PrimeField;
+
+    /// Primitive `n`th root of unity, derived by squaring the field's top-level
+    /// `ROOT_OF_UNITY` (a primitive `2^S`th root) `S - log2(n)` times.
+    pub(super) fn root_of_unity<F: PrimeField>(n: usize) -> F {
+        debug_assert!(n.is_power_of_two());
+        let log_n = n.trailing_zeros();
+        debug_assert!(log_n <= F::S);
+        let mut root = F::ROOT_OF_UNITY;
+        for _ in 0..(F::S - log_n) {
+            root *= root;
+        }
+        root
+    }
+
+    fn bit_reverse<F: PrimeField>(values: &mut [F]) {
+        let n = values.len();
+        if n <= 1 {
+            return;
+        }
+        let bits = n.trailing_zeros();
+        for i in 0..n {
+            let j = (i as u32).reverse_bits() >> (32 - bits);
+            if i < j as usize {
+                values.swap(i, j as usize);
+            }
+        }
+    }
+
+    /// In-place forward NTT: evaluate the polynomial with coefficients `values` at
+    /// `1, omega, omega^2, ...`. `omega` must be a primitive `values.len()`th root of unity.
+    pub(super) fn forward<F: PrimeField>(values: &mut [F], omega: F) {
+        let n = values.len();
+        debug_assert!(n.is_power_of_two());
+        bit_reverse(values);
+        let mut len = 2;
+        while len <= n {
+            let w_len = omega.pow([(n / len) as u64]);
+            for block in values.chunks_mut(len) {
+                let half = len / 2;
+                let mut w = F::ONE;
+                for i in 0..half {
+                    let u = block[i];
+                    let v = block[i + half] * w;
+                    block[i] = u + v;
+                    block[i + half] = u - v;
+                    w *= w_len;
+                }
+            }
+            len <<= 1;
+        }
+    }
+
+    /// In-place inverse NTT: recover monomial coefficients from evaluations on
+    /// `1, omega, omega^2, ...`.
+    pub(super) fn inverse<F: PrimeField>(values: &mut [F], omega: F) {
+        let n = values.len();
+        forward(values, omega.invert().unwrap());
+        let n_inv = F::from(n as u64).invert().unwrap();
+        for v in values.iter_mut() {
+            *v *= n_inv;
+        }
+    }
+}
+
+/// KZG commitments to a divisor's `A(x)`, `B(x)` monomial coefficients, so a constructed
+/// `Divisor` can be bound into a SNARK transcript.
+pub mod kzg {
+    use group::{Curve, Group};
+    use pairing::Engine;
+
+    /// Structured reference string: powers of a secret `tau` in G1, plus `tau` in G2.
+    pub struct Srs<E: Engine> {
+        // [tau^0 * G1, tau^1 * G1, ..., tau^degree * G1]
+        g1_powers: Vec<E::G1>,
+        g2: E::G2,
+        tau_g2: E::G2,
+    }
+
+    impl<E: Engine> Srs<E> {
+        /// Build an SRS for polynomials up to `degree`, from a (trusted) secret `tau` that
+        /// the caller must discard afterwards.
+        pub fn setup(tau: E::Fr, degree: usize) -> Self {
+            let mut g1_powers = Vec::with_capacity(degree + 1);
+            let mut power = E::Fr::ONE;
+            for _ in 0..=degree {
+                g1_powers.push(E::G1::generator() * power);
+                power *= tau;
+            }
+            let g2 = E::G2::generator();
+            Self {
+                g1_powers,
+                g2,
+                tau_g2: g2 * tau,
+            }
+        }
+
+        /// Multiscalar-multiply the SRS by `coeffs`, the monomial coefficients of a
+        /// polynomial, to commit to it.
+        pub fn commit(&self, coeffs: &[E::Fr]) -> E::G1 {
+            debug_assert!(coeffs.len() <= self.g1_powers.len());
+            coeffs
+                .iter()
+                .zip(self.g1_powers.iter())
+                .map(|(c, g)| *g * c)
+                .fold(E::G1::identity(), |acc, term| acc + term)
+        }
+
+        /// Open `coeffs` at `z`, returning `(p(z), proof)` where `proof` commits to the
+        /// quotient `(p(x) - p(z)) / (x - z)`, computed by synthetic division.
+        pub fn open(&self, coeffs: &[E::Fr], z: E::Fr) -> (E::Fr, E::G1) {
+            let value = eval(coeffs, z);
+            let quotient = synthetic_division(coeffs, z);
+            (value, self.commit(&quotient))
+        }
+
+        /// Check that `commitment` opens to `value` at `z` given the opening `proof`:
+        /// e(C - value*G1, G2) == e(proof, tau*G2 - z*G2).
+        pub fn verify(&self, commitment: E::G1, z: E::Fr, value: E::Fr, proof: E::G1) -> bool {
+            let lhs = commitment - self.g1_powers[0] * value;
+            let rhs_g2 = self.tau_g2 - self.g2 * z;
+            E::pairing(&lhs.to_affine(), &self.g2.to_affine())
+                == E::pairing(&proof.to_affine(), &rhs_g2.to_affine())
+        }
+    }
+
+    fn eval<F: ff::PrimeField>(coeffs: &[F], z: F) -> F {
+        coeffs.iter().rev().fold(F::ZERO, |acc, c| acc * z + c)
+    }
+
+    /// Divide `p(x) - p(z)` by `(x - z)`, returning the quotient's coefficients.
+    fn synthetic_division<F: ff::PrimeField>(coeffs: &[F], z: F) -> Vec<F> {
+        let mut quotient = vec![F::ZERO; coeffs.len() - 1];
+        let mut carry = F::ZERO;
+        for (i, c) in coeffs.iter().enumerate().rev() {
+            let term = *c + carry;
+            if i > 0 {
+                quotient[i - 1] = term;
+            }
+            carry = term * z;
+        }
+        quotient
+    }
+}
+
+/// Elliptic curve y^2 = x^3 + ax + b that a `Divisor`'s arithmetic is defined over. Threading
+/// this through `Divisor` (rather than baking `a`/`b` into a shared evaluation vector) lets
+/// the same code serve the multiple cycle/tower fields an FCMP construction needs.
+struct Curve<F: PrimeField> {
+    a: F,
+    b: F,
+}
+
+impl<F: PrimeField> Curve<F> {
+    fn new(a: F, b: F) -> Self {
+        Self { a, b }
+    }
+
+    /// x^3 + ax + b evaluated on the integer domain {0,...,len-1}, for the multiply hot
+    /// loop's y^2 substitution.
+    fn modulus(&self, len: usize) -> Evals<F> {
+        let evals = (0..len)
+            .map(|i| {
+                let x = F::from(i as u64);
+                x * x * x + self.a * x + self.b
+            })
+            .collect();
+        Evals {
+            evals,
+            degree: 3,
+            weights: RefCell::new(None),
+        }
+    }
+}
+
 /// Divisor of form f(x,y) = A(x) - yB(x), with A and B
 /// represented as enough evaluations for their degree.
 struct Divisor<F: PrimeField> {
     a: Evals<F>,
     b: Evals<F>,
-    // to substitute y^2
+    // x^3 + ax + b evaluated on this divisor's domain, to substitute y^2; cached here so
+    // it isn't rebuilt on every multiply
     modulus: Rc<Evals<F>>,
 }
 
@@ -57,10 +237,31 @@ impl<F: PrimeField> Divisor<F> {
         let denominator = Evals {
             evals: denominator,
             degree: 2,
+            weights: RefCell::new(None),
         };
         self / denominator
     }
 
+    /// Evaluate the divisor's components at an arbitrary challenge `z`, returning
+    /// `(A(z), B(z))`.
+    pub fn evaluate(&self, z: F) -> (F, F) {
+        (self.a.evaluate(z), self.b.evaluate(z))
+    }
+
+    /// `(d/dx + lambda*d/dy) log f` at the curve point `(z, y)`: `(A'(z) - yB'(z) -
+    /// lambda*B(z)) / (A(z) - yB(z))`. `y` is the caller-supplied y-coordinate, not
+    /// recovered via `sqrt` (which only determines it up to sign).
+    pub fn log_derivative(&self, z: F, y: F, lambda: F) -> F {
+        let a = self.a.evaluate(z);
+        let b = self.b.evaluate(z);
+        let a_prime = self.a.derivative().evaluate(z);
+        let b_prime = self.b.derivative().evaluate(z);
+
+        let numerator = a_prime - y * b_prime - lambda * b;
+        let denominator = a - y * b;
+        numerator * denominator.invert().unwrap()
+    }
+
     fn merge(divisors: [Self; 2], small: SmallDivisor<F>, denom: (F, F)) -> Self {
         let [d1, d2] = divisors;
         let numerator = d1 * &d2;
@@ -69,6 +270,211 @@ impl<F: PrimeField> Divisor<F> {
         let (x1, x2) = denom;
         numerator.remove_diff(x1, x2)
     }
+
+    /// Build a `Divisor` out of already-computed `a`/`b` evaluations, caching this
+    /// divisor's `x^3+ax+b` modulus from `curve` rather than requiring callers to build it.
+    fn new(a: Evals<F>, b: Evals<F>, curve: &Curve<F>) -> Self {
+        debug_assert_eq!(a.len(), b.len());
+        let modulus = Rc::new(curve.modulus(a.len()));
+        Self { a, b, modulus }
+    }
+
+    /// Whether `p1` and `p2` are distinct points sharing an x-coordinate, i.e. mutual
+    /// inverses under curve negation (`p2 == -p1`). The line through such a pair is
+    /// vertical and has no `y = lambda*x+mu` form, so `chord` cannot be used on them.
+    fn is_vertical_pair(p1: (F, F), p2: (F, F)) -> bool {
+        p1 != p2 && p1.0 == p2.0
+    }
+
+    /// Line through `p1` and `p2` (or the tangent at `p1` when they coincide), as a
+    /// `SmallDivisor` for `lambda*x + mu - y`, plus the line's negated third intersection
+    /// with the curve. Callers must not pass a vertical pair (see `is_vertical_pair`).
+    fn chord(p1: (F, F), p2: (F, F), curve: &Curve<F>) -> (SmallDivisor<F>, (F, F)) {
+        let (x1, y1) = p1;
+        let (x2, y2) = p2;
+        let lambda = if p1 == p2 {
+            (x1 * x1 * F::from(3u64) + curve.a) * (y1 + y1).invert().unwrap()
+        } else {
+            debug_assert!(!Self::is_vertical_pair(p1, p2));
+            (y2 - y1) * (x2 - x1).invert().unwrap()
+        };
+        let mu = y1 - lambda * x1;
+        // sum of the 3 roots of x^3 - lambda^2*x^2 + .. = 0 is lambda^2
+        let x3 = lambda * lambda - x1 - x2;
+        let y3 = lambda * x3 + mu;
+        (SmallDivisor::new((lambda, mu), F::ONE), (x3, -y3))
+    }
+
+    /// Evaluate a `SmallDivisor`'s `A(x) = a.0*x + a.1`, `B(x) = b` on the fixed domain
+    /// `{0,1,...,len-1}` and lift it to a full `Divisor`.
+    fn from_small(small: SmallDivisor<F>, len: usize, curve: &Curve<F>) -> Self {
+        let (slope, intercept) = small.a;
+        let mut a = Vec::with_capacity(len);
+        let mut x = intercept;
+        for _ in 0..len {
+            a.push(x);
+            x += slope;
+        }
+        let b = vec![small.b; len];
+        Self::new(
+            Evals {
+                evals: a,
+                degree: 1,
+                weights: RefCell::new(None),
+            },
+            Evals {
+                evals: b,
+                degree: 0,
+                weights: RefCell::new(None),
+            },
+            curve,
+        )
+    }
+
+    /// Build the leaf divisor for a pair of points, along with its running point: the
+    /// partial sum `p1+p2` (whose negation is the line's spurious third zero), or `None`
+    /// if the pair already sums to the identity
+    /// (a vertical pair), since that divisor's zero set is already complete.
+    fn leaf(p1: (F, F), p2: (F, F), len: usize, curve: &Curve<F>) -> (Self, Option<(F, F)>) {
+        if Self::is_vertical_pair(p1, p2) {
+            // p1 + p2 = O already; the vertical line x - p1.0 has zeros exactly {p1, p2},
+            // with no spurious third point to cancel.
+            let vertical = SmallDivisor::new((F::ONE, -p1.0), F::ZERO);
+            (Self::from_small(vertical, len, curve), None)
+        } else {
+            let (small, third) = Self::chord(p1, p2, curve);
+            (Self::from_small(small, len, curve), Some(third))
+        }
+    }
+
+    /// Combine two subtrees' divisors and running points into one, as `interpolate`'s
+    /// merge step. If both running points are present and are mutual inverses (which must
+    /// happen at the final merge of a full point set summing to the identity, and may
+    /// happen earlier by coincidence), there is no spurious third point to introduce: just
+    /// multiply and divide out the single vertical line `x - r1.x`, whose zeros are exactly
+    /// the two subtrees' spurious points `-r1` and `-r2 = r1`. Otherwise fall back to `merge`,
+    /// connecting the two running points with their chord/tangent line. A running point of
+    /// `None` means that subtree's points already sum to the identity on their own, so no
+    /// cancellation against it is needed at all.
+    fn combine(
+        d1: Self,
+        r1: Option<(F, F)>,
+        d2: Self,
+        r2: Option<(F, F)>,
+        curve: &Curve<F>,
+    ) -> (Self, Option<(F, F)>) {
+        match (r1, r2) {
+            (Some(r1), Some(r2)) if Self::is_vertical_pair(r1, r2) => {
+                let product = d1 * &d2;
+                let vertical = Evals {
+                    evals: (0..product.a.len())
+                        .map(|i| F::from(i as u64) - r1.0)
+                        .collect(),
+                    degree: 1,
+                    weights: RefCell::new(None),
+                };
+                (product / vertical, None)
+            }
+            (Some(r1), Some(r2)) => {
+                let (small, third) = Self::chord(r1, r2, curve);
+                (Self::merge([d1, d2], small, (r1.0, r2.0)), Some(third))
+            }
+            (Some(r), None) | (None, Some(r)) => (d1 * &d2, Some(r)),
+            (None, None) => (d1 * &d2, None),
+        }
+    }
+
+    /// Build the divisor `f(x,y) = A(x) - yB(x)` whose zeros are exactly `points`, a set of
+    /// affine curve points summing to the identity.
+    ///
+    /// Implemented as a balanced subproduct tree: the leaves are the lines through
+    /// consecutive pairs of points, each promoted to a full `Divisor`; siblings are then
+    /// repeatedly combined (`combine`/`merge`), connected by the line through their
+    /// respective running points, until a single divisor remains.
+    pub fn interpolate(points: &[(F, F)], curve: &Curve<F>) -> Self {
+        assert!(!points.is_empty() && points.len() % 2 == 0);
+        // generous bound on A/B's degree through every level, so `debug_assert!(evals.len()
+        // > degree)` holds throughout the whole merge tree
+        let leaves = points.len().div_ceil(2);
+        let len = 3 * leaves + 1;
+
+        let mut level: Vec<(Self, Option<(F, F)>)> = points
+            .chunks(2)
+            .map(|pair| Self::leaf(pair[0], pair[1], len, curve))
+            .collect();
+
+        while level.len() > 1 {
+            let mut next = Vec::with_capacity(level.len().div_ceil(2));
+            let mut siblings = level.into_iter();
+            while let Some((d1, r1)) = siblings.next() {
+                match siblings.next() {
+                    Some((d2, r2)) => {
+                        let (merged, running) = Self::combine(d1, r1, d2, r2, curve);
+                        debug_assert!(merged.a.len() > merged.a.degree);
+                        next.push((merged, running));
+                    }
+                    None => next.push((d1, r1)),
+                }
+            }
+            level = next;
+        }
+
+        let (divisor, running) = level.pop().expect("points is non-empty");
+        debug_assert!(running.is_none(), "interpolate: points must sum to the identity");
+        divisor
+    }
+
+    /// Monomial coefficients of `A(x)` and `B(x)`, recovered by re-evaluating each on a
+    /// roots-of-unity domain (reusing the barycentric `evaluate`) and running the inverse
+    /// NTT. This is an additional representation alongside the integer-domain `Evals`, not
+    /// a replacement, for use where coefficients are needed (commitments, serialization).
+    pub fn to_coeffs(&self) -> (Vec<F>, Vec<F>) {
+        (Self::poly_to_coeffs(&self.a), Self::poly_to_coeffs(&self.b))
+    }
+
+    fn poly_to_coeffs(poly: &Evals<F>) -> Vec<F> {
+        let n = (poly.degree + 1).next_power_of_two();
+        let omega = ntt::root_of_unity::<F>(n);
+        let mut evals = Vec::with_capacity(n);
+        let mut w = F::ONE;
+        for _ in 0..n {
+            evals.push(poly.evaluate(w));
+            w *= omega;
+        }
+        ntt::inverse(&mut evals, omega);
+        evals.truncate(poly.degree + 1);
+        evals
+    }
+
+    /// Inverse of `to_coeffs`: rebuild `A(x)`, `B(x)` in evaluation form on the fixed
+    /// integer domain {0,...,len-1} from their monomial coefficients.
+    pub fn from_coeffs(a_coeffs: &[F], b_coeffs: &[F], len: usize, curve: &Curve<F>) -> Self {
+        Self::new(
+            Self::coeffs_to_poly(a_coeffs, len),
+            Self::coeffs_to_poly(b_coeffs, len),
+            curve,
+        )
+    }
+
+    fn coeffs_to_poly(coeffs: &[F], len: usize) -> Evals<F> {
+        let evals = (0..len)
+            .map(|i| {
+                let x = F::from(i as u64);
+                coeffs.iter().rev().fold(F::ZERO, |acc, c| acc * x + c)
+            })
+            .collect();
+        Evals {
+            evals,
+            degree: coeffs.len() - 1,
+            weights: RefCell::new(None),
+        }
+    }
+
+    /// Commit to this divisor's `A(x)` and `B(x)` under `srs`.
+    pub fn commit<E: pairing::Engine<Fr = F>>(&self, srs: &kzg::Srs<E>) -> (E::G1, E::G1) {
+        let (a_coeffs, b_coeffs) = self.to_coeffs();
+        (srs.commit(&a_coeffs), srs.commit(&b_coeffs))
+    }
 }
 
 impl<F: PrimeField> SmallDivisor<F> {
@@ -80,12 +486,91 @@ impl<F: PrimeField> SmallDivisor<F> {
 struct Evals<F: PrimeField> {
     evals: Vec<F>,
     degree: usize,
+    // barycentric weights w_i = 1/prod_{j!=i}(i-j) for the domain {0,...,len-1}; these
+    // depend only on `evals.len()`, so they're computed once and cached here.
+    weights: RefCell<Option<Rc<Vec<F>>>>,
 }
 
 impl<F: PrimeField> Evals<F> {
     fn len(&self) -> usize {
         self.evals.len()
     }
+
+    fn barycentric_weights(&self) -> Rc<Vec<F>> {
+        if let Some(weights) = self.weights.borrow().as_ref() {
+            return weights.clone();
+        }
+        let len = self.evals.len();
+        let mut weights = Vec::with_capacity(len);
+        for i in 0..len {
+            let mut denom = F::ONE;
+            for j in 0..len {
+                if i != j {
+                    denom *= F::from(i as u64) - F::from(j as u64);
+                }
+            }
+            weights.push(denom);
+        }
+        let mut scratch = weights.clone();
+        ff::BatchInverter::invert_with_external_scratch(&mut weights, &mut scratch);
+        let weights = Rc::new(weights);
+        *self.weights.borrow_mut() = Some(weights.clone());
+        weights
+    }
+
+    /// Evaluate this evaluation-form polynomial at an arbitrary field point `z`, via
+    /// barycentric Lagrange interpolation over the fixed domain {0,...,len-1}.
+    fn evaluate(&self, z: F) -> F {
+        let len = self.evals.len();
+        for i in 0..len {
+            if z == F::from(i as u64) {
+                return self.evals[i];
+            }
+        }
+
+        let weights = self.barycentric_weights();
+        let mut diffs: Vec<F> = (0..len).map(|i| z - F::from(i as u64)).collect();
+        let mut scratch = diffs.clone();
+        ff::BatchInverter::invert_with_external_scratch(&mut diffs, &mut scratch);
+
+        let mut numerator = F::ZERO;
+        let mut denominator = F::ZERO;
+        for i in 0..len {
+            let term = weights[i] * diffs[i];
+            numerator += term * self.evals[i];
+            denominator += term;
+        }
+        numerator * denominator.invert().unwrap()
+    }
+
+    /// This polynomial's derivative, evaluated on the same integer domain {0,...,len-1},
+    /// via the standard barycentric-differentiation formula (reusing `barycentric_weights`):
+    /// for k != j, D_kj = (w_j/w_k) / (x_k - x_j), and D_kk = -sum_{j!=k} D_kj.
+    fn derivative(&self) -> Self {
+        let len = self.evals.len();
+        let weights = self.barycentric_weights();
+        let mut evals = vec![F::ZERO; len];
+        for k in 0..len {
+            let xk = F::from(k as u64);
+            let weight_k_inv = weights[k].invert().unwrap();
+            let mut diagonal = F::ZERO;
+            for j in 0..len {
+                if j == k {
+                    continue;
+                }
+                let xj = F::from(j as u64);
+                let d = weights[j] * (xk - xj).invert().unwrap() * weight_k_inv;
+                evals[k] += d * self.evals[j];
+                diagonal -= d;
+            }
+            evals[k] += diagonal * self.evals[k];
+        }
+        Self {
+            evals,
+            degree: self.degree.saturating_sub(1),
+            weights: RefCell::new(None),
+        }
+    }
 }
 
 /*
@@ -229,3 +714,117 @@ impl<F: PrimeField> Div<Evals<F>> for Divisor<F> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bls12_381::Scalar;
+
+    #[test]
+    fn evaluate_matches_known_polynomial() {
+        // p(x) = 2x^2 + 3x + 1, sampled on the domain {0,1,2,3}
+        let evals = Evals {
+            evals: (0u64..4)
+                .map(|i| {
+                    let x = Scalar::from(i);
+                    Scalar::from(2) * x * x + Scalar::from(3) * x + Scalar::from(1)
+                })
+                .collect(),
+            degree: 2,
+            weights: RefCell::new(None),
+        };
+        let z = Scalar::from(10);
+        let expected = Scalar::from(2) * z * z + Scalar::from(3) * z + Scalar::from(1);
+        assert_eq!(evals.evaluate(z), expected);
+    }
+
+    #[test]
+    fn derivative_matches_known_polynomial() {
+        // p(x) = 2x^2 + 3x + 1 has derivative p'(x) = 4x + 3
+        let evals = Evals {
+            evals: (0u64..4)
+                .map(|i| {
+                    let x = Scalar::from(i);
+                    Scalar::from(2) * x * x + Scalar::from(3) * x + Scalar::from(1)
+                })
+                .collect(),
+            degree: 2,
+            weights: RefCell::new(None),
+        };
+        let z = Scalar::from(10);
+        let expected = Scalar::from(4) * z + Scalar::from(3);
+        assert_eq!(evals.derivative().evaluate(z), expected);
+    }
+
+    #[test]
+    fn coeffs_round_trip() {
+        let curve = Curve::new(Scalar::ZERO, Scalar::from(5));
+        let a_coeffs = vec![Scalar::from(7), Scalar::from(3), Scalar::from(1)];
+        let b_coeffs = vec![Scalar::from(2), Scalar::from(1)];
+        let divisor = Divisor::from_coeffs(&a_coeffs, &b_coeffs, 8, &curve);
+        let (a_out, b_out) = divisor.to_coeffs();
+        assert_eq!(a_out, a_coeffs);
+        assert_eq!(b_out, b_coeffs);
+    }
+
+    #[test]
+    fn interpolate_zeros_a_balanced_point_set() {
+        // toy curve y^2 = x^3 + 1 (a = 0, b = 1)
+        let curve = Curve::new(Scalar::ZERO, Scalar::ONE);
+        let p = (Scalar::from(2), Scalar::from(3)); // 3^2 == 2^3 + 1
+        let q = (Scalar::ZERO, Scalar::ONE); // 1^2 == 0^3 + 1
+        let neg_p = (p.0, -p.1);
+        let neg_q = (q.0, -q.1);
+
+        // p, neg_p, q and neg_q sum to the identity: each pair is mutual inverses, so this
+        // exercises the vertical-pair leaves and their merge directly.
+        let divisor = Divisor::interpolate(&[p, neg_p, q, neg_q], &curve);
+
+        for &(x, y) in &[p, neg_p, q, neg_q] {
+            let (a, b) = divisor.evaluate(x);
+            assert_eq!(a - y * b, Scalar::ZERO);
+        }
+        // sanity: not the zero divisor
+        let (a, b) = divisor.evaluate(Scalar::from(99));
+        assert_ne!((a, b), (Scalar::ZERO, Scalar::ZERO));
+    }
+
+    #[test]
+    fn log_derivative_matches_direct_computation() {
+        let curve = Curve::new(Scalar::ZERO, Scalar::from(5));
+        let a_coeffs = vec![Scalar::from(7), Scalar::from(3), Scalar::from(1)]; // A(x) = x^2+3x+7
+        let b_coeffs = vec![Scalar::from(2), Scalar::from(1)]; // B(x) = x+2
+        let divisor = Divisor::from_coeffs(&a_coeffs, &b_coeffs, 8, &curve);
+
+        let z = Scalar::from(10);
+        let y = Scalar::from(4);
+        let lambda = Scalar::from(6);
+
+        let a = z * z + Scalar::from(3) * z + Scalar::from(7);
+        let b = z + Scalar::from(2);
+        let a_prime = Scalar::from(2) * z + Scalar::from(3);
+        let b_prime = Scalar::ONE;
+
+        let numerator = a_prime - y * b_prime - lambda * b;
+        let denominator = a - y * b;
+        let expected = numerator * denominator.invert().unwrap();
+
+        assert_eq!(divisor.log_derivative(z, y, lambda), expected);
+    }
+
+    #[test]
+    fn kzg_open_verifies_and_rejects_wrong_value() {
+        use bls12_381::Bls12;
+
+        let tau = Scalar::from(12345);
+        let srs = kzg::Srs::<Bls12>::setup(tau, 4);
+        let coeffs = vec![Scalar::from(1), Scalar::from(2), Scalar::from(3)]; // 3x^2 + 2x + 1
+
+        let commitment = srs.commit(&coeffs);
+        let z = Scalar::from(7);
+        let (value, proof) = srs.open(&coeffs, z);
+
+        assert!(srs.verify(commitment, z, value, proof));
+        assert!(!srs.verify(commitment, z, value + Scalar::ONE, proof));
+    }
+}